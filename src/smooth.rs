@@ -22,35 +22,198 @@
 /// value.advance(0.5);
 /// assert_eq!(value.get(), 1.5);
 /// ```
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone)]
 pub struct Smoothed<T> {
-    prev: T,
-    next: T,
-    progress: f32,
+    mode: Mode<T>,
+    style: SmoothingStyle,
+}
+
+/// Domain in which a [`Smoothed`] interpolates between its endpoints
+///
+/// [`Linear`](Self::Linear) is appropriate for most parameters. [`Logarithmic`](Self::Logarithmic)
+/// interpolates in the decibel domain instead, which matches human loudness perception and is
+/// usually the better choice for gain/amplitude parameters such as [`Gain`](crate::Gain)'s, where
+/// a straight linear fade sounds perceptually uneven.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum SmoothingStyle {
+    /// Interpolate the raw value directly
+    #[default]
+    Linear,
+    /// Interpolate in the decibel domain, converting back to linear amplitude in [`get`](Smoothed::get)
+    Logarithmic,
+}
+
+/// Interpolate `a` towards `b` by `t`, in the domain selected by `style`
+fn lerp_styled<T: Interpolate>(a: &T, b: &T, t: f32, style: SmoothingStyle) -> T {
+    match style {
+        SmoothingStyle::Linear => a.interpolate(b, t),
+        SmoothingStyle::Logarithmic => {
+            let da = a.to_db();
+            let db = b.to_db();
+            da.interpolate(&db, t).db_to_linear()
+        }
+    }
+}
+
+/// Interpolation strategy used by a particular [`Smoothed`] instance
+#[derive(Copy, Clone)]
+enum Mode<T> {
+    /// Linear ramp from `prev` to `next`, reaching `next` once `progress >= 1.0`
+    Linear { prev: T, next: T, progress: f32 },
+    /// Exponential "smooth-follow" that asymptotically chases `target`, never formally arriving
+    HalfLife { value: T, target: T, half_life: f32 },
+}
+
+impl<T> Default for Mode<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Mode::Linear {
+            prev: T::default(),
+            next: T::default(),
+            progress: 1.0,
+        }
+    }
+}
+
+impl<T> Default for Smoothed<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            mode: Mode::default(),
+            style: SmoothingStyle::default(),
+        }
+    }
 }
 
 impl<T> Smoothed<T> {
-    /// Create with initial value `x`
+    /// Create with initial value `x`, using the default linear ramp behavior
     pub fn new(x: T) -> Self
     where
         T: Clone,
     {
         Self {
-            prev: x.clone(),
-            next: x,
-            progress: 1.0,
+            mode: Mode::Linear {
+                prev: x.clone(),
+                next: x,
+                progress: 1.0,
+            },
+            style: SmoothingStyle::default(),
+        }
+    }
+
+    /// Select the domain in which this value is interpolated; see [`SmoothingStyle`]
+    pub fn with_style(mut self, style: SmoothingStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Create with initial value `x`, chasing future targets exponentially with half-life `h`
+    ///
+    /// Each call to [`advance`](Self::advance) moves the current value a fraction of the way to
+    /// the target, such that the remaining distance halves every `h` units of `dt`. Unlike the
+    /// linear ramp, this mode is subdivision-stable: advancing by `dt` twice has the same effect
+    /// as advancing once by `2 * dt`, which matters when callers drive it from audio callbacks
+    /// that deliver variable buffer sizes. Because it only approaches the target asymptotically,
+    /// use [`is_settled`](Self::is_settled) to detect when it's close enough to stop advancing.
+    pub fn with_half_life(x: T, half_life: f32) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            mode: Mode::HalfLife {
+                value: x.clone(),
+                target: x,
+                half_life,
+            },
+            style: SmoothingStyle::default(),
         }
     }
 
-    /// Advance interpolation by `proportion`. For example, to advance at a fixed sample rate over a
-    /// particular smoothing period, pass `sample_interval / smoothing_period`.
-    pub fn advance(&mut self, proportion: f32) {
-        self.progress = (self.progress + proportion).min(1.0);
+    /// Advance interpolation by `proportion`, or by elapsed time `dt` in [`with_half_life`](Self::with_half_life) mode.
+    ///
+    /// For the linear ramp, to advance at a fixed sample rate over a particular smoothing
+    /// period, pass `sample_interval / smoothing_period`. For the exponential mode, pass the
+    /// elapsed time directly, in the same units as the configured half-life.
+    pub fn advance(&mut self, dt: f32)
+    where
+        T: Interpolate,
+    {
+        let style = self.style;
+        match &mut self.mode {
+            Mode::Linear { progress, .. } => {
+                *progress = (*progress + dt).min(1.0);
+            }
+            Mode::HalfLife {
+                value,
+                target,
+                half_life,
+            } => {
+                let d = 2f32.powf(-dt / *half_life);
+                *value = lerp_styled(target, value, d, style);
+            }
+        }
     }
 
-    /// Progress from the previous towards the next value
+    /// Progress from the previous towards the next value, for the linear ramp
+    ///
+    /// Always `1.0` in [`with_half_life`](Self::with_half_life) mode, which has no fixed endpoint; use
+    /// [`is_settled`](Self::is_settled) there instead.
     pub fn progress(&self) -> f32 {
-        self.progress
+        match &self.mode {
+            Mode::Linear { progress, .. } => *progress,
+            Mode::HalfLife { .. } => 1.0,
+        }
+    }
+
+    /// Number of calls to [`advance`](Self::advance) with the given fixed `proportion` remaining
+    /// before interpolation completes
+    ///
+    /// Always `0` in [`with_half_life`](Self::with_half_life) mode, which never formally
+    /// completes; use [`is_settled`](Self::is_settled) there instead.
+    pub fn steps_remaining(&self, proportion: f32) -> usize {
+        (((1.0 - self.progress()) / proportion).ceil().max(0.0)) as usize
+    }
+
+    /// Iterate over successive values of this parameter, advancing by `proportion` after each
+    ///
+    /// Equivalent to alternately calling [`get`](Self::get) and [`advance`](Self::advance) by
+    /// hand, which is useful when a filter wants to drive a smoothed parameter over a whole block
+    /// of samples:
+    /// ```ignore
+    /// for (out, g) in frames.iter_mut().zip(gain.iter(interval)) {
+    ///     *out *= g;
+    /// }
+    /// ```
+    /// The iterator never ends by itself — once interpolation completes it keeps yielding the
+    /// settled target — so pair it with [`steps_remaining`](Self::steps_remaining) or
+    /// [`Iterator::zip`] against a bounded sequence.
+    pub fn iter(&mut self, proportion: f32) -> Iter<'_, T>
+    where
+        T: Interpolate,
+    {
+        Iter {
+            smoothed: self,
+            proportion,
+        }
+    }
+
+    /// Whether the current value is within `epsilon` of the target
+    ///
+    /// For the linear ramp this is equivalent to `progress() >= 1.0`. For the exponential mode,
+    /// which never formally reaches its target, this lets callers (e.g. [`Gain`](crate::Gain))
+    /// stop calling [`advance`](Self::advance) once further change would be imperceptible.
+    pub fn is_settled(&self, epsilon: f32) -> bool
+    where
+        T: Interpolate,
+    {
+        match &self.mode {
+            Mode::Linear { progress, .. } => *progress >= 1.0,
+            Mode::HalfLife { value, target, .. } => value.sub(target).max_abs() <= epsilon,
+        }
     }
 
     /// Set the next value to `x`
@@ -63,14 +226,37 @@ impl<T> Smoothed<T> {
         //  self.prev and new value, then set progress based on current progress
         //  value converted to this line
 
-        if self.progress < 1. && (value - self.get()).sign() == (value - self.prev).sign() {
-            let current = self.get();
-            self.next = value;
-            self.progress = ((current - self.prev) / (self.next - current)).to_f32();
-        } else {
-            self.prev = self.get();
-            self.next = value;
-            self.progress = 0.0;
+        let style = self.style;
+        match &mut self.mode {
+            Mode::Linear { prev, next, progress } => {
+                let current = lerp_styled(prev, next, *progress, style);
+                let denom = value.sub(&current);
+                let ratios = current.sub(prev).div(&denom);
+                let candidate = ratios.to_f32();
+                // A zero (or otherwise non-finite) per-channel denominator happens whenever some
+                // channel's new target exactly equals its current value; feeding that into
+                // `interpolate` would poison every channel with inf/NaN, so fall back to a clean
+                // restart instead of taking the fast path in that case. Likewise, per-channel
+                // ratios that agree in sign but not in magnitude would have `candidate` (their
+                // mean) stand in for every channel, so require them to roughly agree before
+                // trusting it. Even when they do agree, `candidate` is unbounded above, so it's
+                // clamped to the valid progress range rather than letting `get()` overshoot.
+                if *progress < 1.
+                    && candidate.is_finite()
+                    && denom.sign() == next.sub(prev).sign()
+                    && ratios.channel_spread() <= CONTINUITY_TOLERANCE
+                {
+                    *next = value;
+                    *progress = candidate.clamp(0.0, 1.0);
+                } else {
+                    *prev = current;
+                    *next = value;
+                    *progress = 0.0;
+                }
+            }
+            Mode::HalfLife { target, .. } => {
+                *target = value;
+            }
         }
     }
 
@@ -79,33 +265,254 @@ impl<T> Smoothed<T> {
     where
         T: Interpolate,
     {
-        self.prev.interpolate(&self.next, self.progress)
+        match &self.mode {
+            Mode::Linear { prev, next, progress } => lerp_styled(prev, next, *progress, self.style),
+            Mode::HalfLife { value, .. } => *value,
+        }
     }
 
     /// Get the value most recently passed to `set`
     pub fn target(&self) -> &T {
-        &self.next
+        match &self.mode {
+            Mode::Linear { next, .. } => next,
+            Mode::HalfLife { target, .. } => target,
+        }
+    }
+}
+
+/// Iterator over successive values of a [`Smoothed`], advancing it by a fixed proportion each step
+///
+/// Created by [`Smoothed::iter`].
+pub struct Iter<'a, T> {
+    smoothed: &'a mut Smoothed<T>,
+    proportion: f32,
+}
+
+impl<'a, T: Interpolate> Iterator for Iter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let value = self.smoothed.get();
+        self.smoothed.advance(self.proportion);
+        Some(value)
+    }
+}
+
+/// A time-stamped value in an [`Envelope`]'s timeline
+#[derive(Copy, Clone)]
+pub struct Keyframe<T> {
+    /// Time at which this keyframe takes effect
+    pub time: f32,
+    /// Value at this keyframe
+    pub value: T,
+    /// How to interpolate from this keyframe towards the next
+    pub curve: Curve,
+}
+
+impl<T> Keyframe<T> {
+    /// Construct a keyframe of `value` at `time`, interpolating towards the next keyframe via `curve`
+    pub fn new(time: f32, value: T, curve: Curve) -> Self {
+        Self { time, value, curve }
+    }
+}
+
+/// Per-segment interpolation mode for [`Envelope`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Curve {
+    /// Hold the segment's starting value until the next keyframe
+    Step,
+    /// Linearly interpolate between the segment's endpoints
+    Linear,
+    /// Smoothly interpolate using a Catmull-Rom-style cubic Hermite spline through neighboring keyframes
+    CubicHermite,
+}
+
+/// A sequence of time-stamped [`Keyframe`]s, sampled to drive scripted parameter automation
+///
+/// Unlike [`Smoothed`], which only tracks a single `prev -> next` segment, `Envelope` holds an
+/// entire timeline and can express volume/filter sweeps with curves a single linear ramp can't,
+/// such as smooth spline automation recorded from a DAW-style keyframe editor.
+///
+/// # Example
+/// ```
+/// use oddio::{Curve, Envelope, Keyframe};
+///
+/// let env = Envelope::new(vec![
+///     Keyframe::new(0.0, 0.0f32, Curve::Linear),
+///     Keyframe::new(1.0, 1.0f32, Curve::Linear),
+/// ]);
+/// assert_eq!(env.sample(0.5), 0.5);
+/// // Sampling outside the timeline clamps to the nearest endpoint
+/// assert_eq!(env.sample(-1.0), 0.0);
+/// assert_eq!(env.sample(2.0), 1.0);
+/// ```
+#[derive(Clone)]
+pub struct Envelope<T> {
+    keyframes: alloc::vec::Vec<Keyframe<T>>,
+}
+
+impl<T> Envelope<T> {
+    /// Create an envelope from a sequence of keyframes sorted by ascending `time`
+    ///
+    /// Panics if `keyframes` is empty.
+    pub fn new(keyframes: alloc::vec::Vec<Keyframe<T>>) -> Self {
+        assert!(!keyframes.is_empty(), "Envelope requires at least one keyframe");
+        debug_assert!(
+            keyframes.windows(2).all(|w| w[0].time <= w[1].time),
+            "keyframes must be sorted by ascending time"
+        );
+        Self { keyframes }
+    }
+
+    /// Sample the envelope at `time`, clamped to the first/last keyframe's value outside the
+    /// timeline's range
+    pub fn sample(&self, time: f32) -> T
+    where
+        T: Interpolate,
+    {
+        let kfs = &self.keyframes;
+        let last = kfs.len() - 1;
+        if time <= kfs[0].time {
+            return kfs[0].value;
+        }
+        if time >= kfs[last].time {
+            return kfs[last].value;
+        }
+
+        let i = match kfs.binary_search_by(|k| k.time.partial_cmp(&time).unwrap()) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let (k0, k1) = (&kfs[i], &kfs[i + 1]);
+        let dt = k1.time - k0.time;
+        let u = (time - k0.time) / dt;
+
+        match k0.curve {
+            Curve::Step => k0.value,
+            Curve::Linear => k0.value.interpolate(&k1.value, u),
+            Curve::CubicHermite => {
+                let m0 = if i == 0 {
+                    k1.value.sub(&k0.value).scale(1.0 / dt)
+                } else {
+                    let km1 = &kfs[i - 1];
+                    k1.value.sub(&km1.value).scale(1.0 / (k1.time - km1.time))
+                };
+                let m1 = if i + 2 > last {
+                    k1.value.sub(&k0.value).scale(1.0 / dt)
+                } else {
+                    let k2 = &kfs[i + 2];
+                    k2.value.sub(&k0.value).scale(1.0 / (k2.time - k0.time))
+                };
+
+                let u2 = u * u;
+                let u3 = u2 * u;
+                let h00 = 2.0 * u3 - 3.0 * u2 + 1.0;
+                let h10 = u3 - 2.0 * u2 + u;
+                let h01 = -2.0 * u3 + 3.0 * u2;
+                let h11 = u3 - u2;
+
+                k0.value
+                    .scale(h00)
+                    .add(&m0.scale(dt * h10))
+                    .add(&k1.value.scale(h01))
+                    .add(&m1.scale(dt * h11))
+            }
+        }
     }
 }
 
 /// Types that can be linearly interpolated, for use with [`Smoothed`]
-pub trait Interpolate:
-    core::ops::Sub<Output = Self> + core::ops::Div<Output = Self> + Sized + Copy + Clone
-{
+///
+/// Implemented for `f32`, `f64`, and fixed-size arrays of any `Interpolate` type (e.g.
+/// `[f32; 2]` for stereo gain), the latter componentwise. Subtraction and division are exposed as
+/// methods rather than via `core::ops` so that array impls don't run afoul of the orphan rule.
+pub trait Interpolate: Sized + Copy + Clone + PartialEq {
     /// Interpolate between `self` and `other` by `t`, which should be in [0, 1]
+    ///
+    /// Exact at the endpoints (`interpolate(a, b, 0.0) == a`, `interpolate(a, b, 1.0) == b`) and
+    /// monotone, never overshooting the `[self, other]` range.
     fn interpolate(&self, other: &Self, t: f32) -> Self;
 
-    /// Signum
-    fn sign(&self) -> f32;
+    /// `self - other`, componentwise for multi-channel types
+    fn sub(&self, other: &Self) -> Self;
+
+    /// `self / other`, componentwise for multi-channel types
+    fn div(&self, other: &Self) -> Self;
 
-    /// convert to float
+    /// `self + other`, componentwise for multi-channel types
+    fn add(&self, other: &Self) -> Self;
+
+    /// `self * t`, componentwise for multi-channel types
+    fn scale(&self, t: f32) -> Self;
+
+    /// Signum, componentwise for multi-channel types
+    ///
+    /// Used by [`Smoothed::set`] to decide whether a new target continues in the same direction
+    /// as the current ramp; for multi-channel types the no-restart fast path is only taken when
+    /// every channel agrees, since [`PartialEq`] compares the whole value.
+    fn sign(&self) -> Self;
+
+    /// Convert to a representative scalar
+    ///
+    /// For multi-channel types this is the mean across channels. Used where some single number is
+    /// needed to stand in for the whole value, e.g. the recomputed `progress` in [`Smoothed::set`].
     fn to_f32(&self) -> f32;
+
+    /// Largest absolute-value component, for magnitude comparisons like [`Smoothed::is_settled`]
+    ///
+    /// Unlike [`to_f32`](Self::to_f32), this never lets channels of opposing sign cancel out, so a
+    /// multi-channel value is only considered close to another if every channel is.
+    fn max_abs(&self) -> f32;
+
+    /// Difference between the largest and smallest channel, `0` for single-channel types
+    ///
+    /// Used by [`Smoothed::set`] to tell whether every channel's recomputed fast-path ratio is
+    /// close enough to [`to_f32`](Self::to_f32)'s mean to share it, rather than just agreeing in
+    /// sign.
+    fn channel_spread(&self) -> f32;
+
+    /// Convert to the decibel domain, for use with [`SmoothingStyle::Logarithmic`]
+    ///
+    /// Implementations should clamp near-zero values to a floor rather than producing `-inf`.
+    /// Multi-channel types convert each channel independently.
+    fn to_db(&self) -> Self;
+
+    /// Inverse of [`to_db`](Self::to_db)
+    fn db_to_linear(&self) -> Self;
 }
 
+/// Amplitudes at or below this are treated as silence by the decibel conversion, to avoid `log(0)`
+const SILENCE_DB: f32 = -100.0;
+
+/// Largest [`Interpolate::channel_spread`] the [`Smoothed::set`] fast path will tolerate before
+/// falling back to a restart, rather than letting one channel's ratio stand in for all of them
+const CONTINUITY_TOLERANCE: f32 = 0.05;
+
 impl Interpolate for f32 {
     fn interpolate(&self, other: &Self, t: f32) -> Self {
-        let diff = other - self;
-        self + t * diff
+        // Evaluated from whichever endpoint `t` is closer to, so the result is exact there even
+        // when `self` and `other` differ greatly in magnitude.
+        if t <= 0.5 {
+            self + t * (other - self)
+        } else {
+            t * other + (1.0 - t) * self
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        self / other
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn scale(&self, t: f32) -> Self {
+        self * t
     }
 
     fn sign(&self) -> f32 {
@@ -119,6 +526,160 @@ impl Interpolate for f32 {
     fn to_f32(&self) -> f32 {
         *self
     }
+
+    fn max_abs(&self) -> f32 {
+        self.abs()
+    }
+
+    fn channel_spread(&self) -> f32 {
+        0.0
+    }
+
+    fn to_db(&self) -> Self {
+        if *self <= 10f32.powf(SILENCE_DB / 20.0) {
+            SILENCE_DB
+        } else {
+            20.0 * self.log10()
+        }
+    }
+
+    fn db_to_linear(&self) -> Self {
+        if *self <= SILENCE_DB {
+            0.0
+        } else {
+            10f32.powf(self / 20.0)
+        }
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let t = t as f64;
+        if t <= 0.5 {
+            self + t * (other - self)
+        } else {
+            t * other + (1.0 - t) * self
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        self / other
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn scale(&self, t: f32) -> Self {
+        self * t as f64
+    }
+
+    fn sign(&self) -> f64 {
+        if self.is_sign_positive() {
+            1.
+        } else {
+            -1.
+        }
+    }
+
+    fn to_f32(&self) -> f32 {
+        *self as f32
+    }
+
+    fn max_abs(&self) -> f32 {
+        self.abs() as f32
+    }
+
+    fn channel_spread(&self) -> f32 {
+        0.0
+    }
+
+    fn to_db(&self) -> Self {
+        if *self <= 10f64.powf(SILENCE_DB as f64 / 20.0) {
+            SILENCE_DB as f64
+        } else {
+            20.0 * self.log10()
+        }
+    }
+
+    fn db_to_linear(&self) -> Self {
+        if *self <= SILENCE_DB as f64 {
+            0.0
+        } else {
+            10f64.powf(self / 20.0)
+        }
+    }
+}
+
+impl<T: Interpolate, const N: usize> Interpolate for [T; N] {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        core::array::from_fn(|i| self[i].interpolate(&other[i], t))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].sub(&other[i]))
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].div(&other[i]))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].add(&other[i]))
+    }
+
+    fn scale(&self, t: f32) -> Self {
+        core::array::from_fn(|i| self[i].scale(t))
+    }
+
+    fn sign(&self) -> Self {
+        core::array::from_fn(|i| self[i].sign())
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.iter().map(Interpolate::to_f32).sum::<f32>() / N as f32
+    }
+
+    fn max_abs(&self) -> f32 {
+        self.iter().map(Interpolate::max_abs).fold(0.0, f32::max)
+    }
+
+    fn channel_spread(&self) -> f32 {
+        let (min, max) = self
+            .iter()
+            .map(Interpolate::to_f32)
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+                (min.min(x), max.max(x))
+            });
+        max - min
+    }
+
+    fn to_db(&self) -> Self {
+        core::array::from_fn(|i| self[i].to_db())
+    }
+
+    fn db_to_linear(&self) -> Self {
+        core::array::from_fn(|i| self[i].db_to_linear())
+    }
+}
+
+#[test]
+fn iter_advances_and_steps_remaining() {
+    let mut s = Smoothed::new(0f32);
+    s.set(1.0);
+
+    assert_eq!(s.steps_remaining(0.25), 4);
+    let values: Vec<f32> = s.iter(0.25).take(4).collect();
+    assert_eq!(values, vec![0.0, 0.25, 0.5, 0.75]);
+    assert_eq!(s.get(), 1.0);
+    assert_eq!(s.steps_remaining(0.25), 0);
+
+    // The iterator keeps yielding the settled target rather than ending
+    assert_eq!(s.iter(0.25).take(3).collect::<Vec<_>>(), vec![1.0, 1.0, 1.0]);
 }
 
 #[test]
@@ -132,3 +693,178 @@ fn repeated_set() {
 
     assert!((s.get() - 1.).abs() < 0.01);
 }
+
+#[test]
+fn set_past_current_clamps_progress_instead_of_overshooting() {
+    let mut s = Smoothed::new(0f32);
+    s.set(10.0);
+    s.advance(0.5);
+    // The new target is barely past the current value, in the same direction as before: the
+    // recomputed fast-path progress is far above 1.0 and must be clamped rather than blown
+    // through `interpolate`'s extrapolating branch.
+    s.set(5.1);
+    assert!(s.progress() <= 1.0);
+    assert!(s.get() <= 5.1);
+}
+
+#[test]
+fn stereo_frame_disagreeing_magnitudes_restarts_instead_of_averaging() {
+    let mut s = Smoothed::new([0f32, 0.0]);
+    s.set([10.0, 1.0]);
+    s.advance(0.5);
+    // Both channels continue in the same direction, but by very different magnitudes: channel 0
+    // still has far to go while channel 1 is nearly there. Averaging the two ratios into one
+    // `progress` would overshoot channel 1; the fast path must be skipped in favor of a restart.
+    s.set([20.0, 0.6]);
+    assert_eq!(s.progress(), 0.0);
+    let value = s.get();
+    assert!(value[0] <= 20.0);
+    assert!(value[1] <= 0.6);
+}
+
+#[test]
+fn logarithmic_style_reaches_endpoints() {
+    let mut s = Smoothed::new(1.0f32).with_style(SmoothingStyle::Logarithmic);
+    s.set(0.001);
+    assert_eq!(s.get(), 1.0);
+    s.advance(1.0);
+    assert!((s.get() - 0.001).abs() < 1e-6);
+}
+
+#[test]
+fn logarithmic_style_fades_to_silence() {
+    let mut s = Smoothed::new(1.0f32).with_style(SmoothingStyle::Logarithmic);
+    s.set(0.0);
+    s.advance(1.0);
+    assert_eq!(s.get(), 0.0);
+}
+
+#[test]
+fn half_life_subdivision_stable() {
+    let mut a = Smoothed::with_half_life(0f32, 1.0);
+    a.set(1.0);
+    a.advance(2.0);
+
+    let mut b = Smoothed::with_half_life(0f32, 1.0);
+    b.set(1.0);
+    b.advance(1.0);
+    b.advance(1.0);
+
+    assert!((a.get() - b.get()).abs() < 1e-6);
+}
+
+#[test]
+fn half_life_settles() {
+    let mut s = Smoothed::with_half_life(0f32, 0.01);
+    s.set(1.0);
+    assert!(!s.is_settled(0.001));
+    for _ in 0..1000 {
+        s.advance(0.001);
+    }
+    assert!(s.is_settled(0.001));
+    assert!(s.get() <= 1.0);
+}
+
+#[test]
+fn envelope_linear_and_step() {
+    let env = Envelope::new(vec![
+        Keyframe::new(0.0, 0.0f32, Curve::Linear),
+        Keyframe::new(1.0, 2.0f32, Curve::Step),
+        Keyframe::new(2.0, 4.0f32, Curve::Linear),
+    ]);
+
+    assert_eq!(env.sample(-1.0), 0.0);
+    assert_eq!(env.sample(0.5), 1.0);
+    assert_eq!(env.sample(1.0), 2.0);
+    assert_eq!(env.sample(1.5), 2.0);
+    assert_eq!(env.sample(2.0), 4.0);
+    assert_eq!(env.sample(3.0), 4.0);
+}
+
+#[test]
+fn envelope_cubic_hermite_passes_through_keys() {
+    let env = Envelope::new(vec![
+        Keyframe::new(0.0, 0.0f32, Curve::CubicHermite),
+        Keyframe::new(1.0, 1.0f32, Curve::CubicHermite),
+        Keyframe::new(2.0, 0.0f32, Curve::CubicHermite),
+    ]);
+
+    assert!((env.sample(0.0) - 0.0).abs() < 1e-6);
+    assert!((env.sample(1.0) - 1.0).abs() < 1e-6);
+    assert!((env.sample(2.0) - 0.0).abs() < 1e-6);
+}
+
+#[test]
+fn envelope_cubic_hermite_for_f64_and_frames() {
+    let f64_env = Envelope::new(vec![
+        Keyframe::new(0.0, 0.0f64, Curve::CubicHermite),
+        Keyframe::new(1.0, 1.0f64, Curve::CubicHermite),
+    ]);
+    assert!((f64_env.sample(0.0) - 0.0).abs() < 1e-9);
+
+    let stereo_env = Envelope::new(vec![
+        Keyframe::new(0.0, [0.0f32, 1.0], Curve::CubicHermite),
+        Keyframe::new(1.0, [1.0f32, 0.0], Curve::CubicHermite),
+    ]);
+    let value = stereo_env.sample(0.0);
+    assert!((value[0] - 0.0).abs() < 1e-6);
+    assert!((value[1] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn interpolate_exact_at_endpoints() {
+    assert_eq!(Interpolate::interpolate(&1e10_f32, &1e-10_f32, 0.0), 1e10);
+    assert_eq!(Interpolate::interpolate(&1e10_f32, &1e-10_f32, 1.0), 1e-10);
+    assert_eq!(Interpolate::interpolate(&1e10_f64, &1e-10_f64, 0.0), 1e10);
+    assert_eq!(Interpolate::interpolate(&1e10_f64, &1e-10_f64, 1.0), 1e-10);
+}
+
+#[test]
+fn f64_smoothed() {
+    let mut s = Smoothed::new(0f64);
+    s.set(1.0);
+    s.advance(0.5);
+    assert_eq!(s.get(), 0.5);
+}
+
+#[test]
+fn stereo_frame_smoothed() {
+    let mut s = Smoothed::new([0f32, 1.0]);
+    s.set([1.0, 0.0]);
+    s.advance(0.5);
+    assert_eq!(s.get(), [0.5, 0.5]);
+}
+
+#[test]
+fn stereo_frame_restart_requires_all_channels_to_agree() {
+    let mut s = Smoothed::new([0f32, 0.0]);
+    s.set([1.0, 1.0]);
+    s.advance(0.5);
+    // Left continues in the same direction as before, right reverses: since the channels
+    // disagree, the fast path must not be taken and the ramp should restart from `get()`.
+    s.set([2.0, -1.0]);
+    assert_eq!(s.progress(), 0.0);
+    assert_eq!(s.target(), &[2.0, -1.0]);
+}
+
+#[test]
+fn stereo_frame_zero_denominator_restarts_instead_of_poisoning() {
+    let mut s = Smoothed::new([0f32, 0.0]);
+    s.set([1.0, 1.0]);
+    s.advance(0.5);
+    // Channel 0's new target exactly equals its current value, which would divide by zero if fed
+    // into the fast path; this must fall back to a clean restart rather than yielding NaN.
+    s.set([0.5, 2.0]);
+    assert!(s.progress().is_finite());
+    let value = s.get();
+    assert!(value[0].is_finite());
+    assert!(value[1].is_finite());
+}
+
+#[test]
+fn half_life_is_settled_checks_every_channel() {
+    let mut s = Smoothed::with_half_life([0f32, 0.0], 0.01);
+    s.set([1000.0, -1000.0]);
+    // Both channels are far from their targets; a signed mean must not let them cancel out.
+    assert!(!s.is_settled(0.001));
+}